@@ -0,0 +1,105 @@
+//! Provides natural-language parsing for todo item due dates.
+
+use chrono::prelude::*;
+use chrono::Duration;
+use thiserror::Error;
+
+/// Represents an error when a due date string could not be understood.
+#[derive(Error, Debug)]
+pub enum DueDateError {
+    #[error("could not understand the given due date")]
+    Unrecognized,
+}
+
+/// Parses `input` as a due date relative to `now`, returning the resolved
+/// date time in UTC.
+///
+/// Fixed formats (`%Y-%m-%d`, `%Y-%m-%d %H:%M`) are tried first. Failing
+/// that, `input` is tokenized and matched against relative expressions
+/// such as "today", "tomorrow", "in 3 days", or a weekday name like
+/// "next monday".
+///
+/// # Errors
+///
+/// A `DueDateError::Unrecognized` error is returned if `input` does not
+/// match any supported format.
+pub fn parse_due_date(input: &str, now: DateTime<Local>) -> Result<DateTime<Utc>, DueDateError> {
+    if let Some(naive) = parse_fixed_format(input.trim()) {
+        return local_to_utc(naive, &now);
+    }
+
+    let lower = input.trim().to_lowercase();
+    let tokens: Vec<&str> = lower.split_whitespace().collect();
+
+    match tokens.as_slice() {
+        ["today"] => Ok(now.with_timezone(&Utc)),
+        ["tomorrow"] => Ok((now + Duration::days(1)).with_timezone(&Utc)),
+        ["in", n, unit] => parse_relative_duration(n, unit).and_then(|duration| {
+            now.checked_add_signed(duration)
+                .map(|dt| dt.with_timezone(&Utc))
+                .ok_or(DueDateError::Unrecognized)
+        }),
+        [.., last] => parse_weekday(last)
+            .map(|weekday| (now + Duration::days(days_until(now.weekday(), weekday))).with_timezone(&Utc))
+            .ok_or(DueDateError::Unrecognized),
+        [] => Err(DueDateError::Unrecognized),
+    }
+}
+
+/// Tries `%Y-%m-%d %H:%M` followed by `%Y-%m-%d`.
+fn parse_fixed_format(input: &str) -> Option<NaiveDateTime> {
+    if let Ok(datetime) = NaiveDateTime::parse_from_str(input, "%Y-%m-%d %H:%M") {
+        return Some(datetime);
+    }
+
+    NaiveDate::parse_from_str(input, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+}
+
+/// Interprets `naive` as a local date time alongside `now` and converts it to UTC.
+fn local_to_utc(naive: NaiveDateTime, now: &DateTime<Local>) -> Result<DateTime<Utc>, DueDateError> {
+    now.timezone()
+        .from_local_datetime(&naive)
+        .single()
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok_or(DueDateError::Unrecognized)
+}
+
+/// Parses an "in N days|weeks|hours" expression into a `Duration`.
+fn parse_relative_duration(n: &str, unit: &str) -> Result<Duration, DueDateError> {
+    let n: i64 = n.parse().map_err(|_| DueDateError::Unrecognized)?;
+
+    match unit {
+        "hour" | "hours" => Duration::try_hours(n),
+        "day" | "days" => Duration::try_days(n),
+        "week" | "weeks" => Duration::try_weeks(n),
+        _ => return Err(DueDateError::Unrecognized),
+    }
+    .ok_or(DueDateError::Unrecognized)
+}
+
+/// Parses a weekday name, e.g. "monday" or "mon".
+fn parse_weekday(name: &str) -> Option<Weekday> {
+    match name {
+        "monday" | "mon" => Some(Weekday::Mon),
+        "tuesday" | "tue" => Some(Weekday::Tue),
+        "wednesday" | "wed" => Some(Weekday::Wed),
+        "thursday" | "thu" => Some(Weekday::Thu),
+        "friday" | "fri" => Some(Weekday::Fri),
+        "saturday" | "sat" => Some(Weekday::Sat),
+        "sunday" | "sun" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// Returns the number of days from `current` to the next occurrence of
+/// `target`, always between 1 and 7 inclusive.
+fn days_until(current: Weekday, target: Weekday) -> i64 {
+    let diff = (target.num_days_from_monday() as i64 - current.num_days_from_monday() as i64 + 7) % 7;
+    if diff == 0 {
+        7
+    } else {
+        diff
+    }
+}
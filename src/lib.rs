@@ -0,0 +1,13 @@
+//! Library crate backing the `rust-todo-list` command-line application.
+
+pub mod due;
+pub mod models;
+pub mod render;
+pub mod serialize;
+pub mod taskwarrior;
+
+pub use due::*;
+pub use models::*;
+pub use render::*;
+pub use serialize::*;
+pub use taskwarrior::*;
@@ -2,6 +2,7 @@ use std::cmp::Ordering;
 use std::io;
 use std::io::Write;
 
+use chrono::{DateTime, Local, Utc};
 use thiserror::Error;
 
 use rust_todo_list::*;
@@ -12,7 +13,15 @@ const HELP_MESSAGE: &str = "\
 2. Remove an existing item
 3. Set an item as complete/incomplete
 4. Move an item up or down the list
-5. Show this help message
+5. Set a due date for an item
+6. Import tasks from Taskwarrior JSON
+7. Export tasks to Taskwarrior JSON
+8. Create a new list
+9. Switch to a different list
+10. Rename the current list
+11. Delete the current list
+12. Change the list display mode
+13. Show this help message
 0. Quit\
 ";
 
@@ -23,6 +32,14 @@ pub enum Command {
     Remove(usize),
     ToggleCompletion(usize),
     Move(usize, usize),
+    SetDue(usize, DateTime<Utc>),
+    Import,
+    Export,
+    CreateList(String),
+    SwitchList(usize),
+    RenameList(usize, String),
+    DeleteList(usize),
+    SetRenderMode(RenderMode),
     Help,
     Quit,
 }
@@ -32,16 +49,31 @@ pub enum Command {
 pub enum CommandError {
     #[error("{0} items are required")]
     InsufficientItems(usize),
+    #[error("{0} lists are required")]
+    InsufficientLists(usize),
     #[error("unknown choice provided")]
     UnknownChoice,
 }
 
-/// Provides a command-line user interface for interacting with a `TodoList`.
+/// Provides a command-line user interface for interacting with a
+/// `TodoCollection`.
 pub struct ProgramInterface {
-    todo_list: TodoList,
+    collection: TodoCollection,
+    active: usize,
+    render_mode: RenderMode,
 }
 
 impl ProgramInterface {
+    /// Returns the `TodoList` that is currently active.
+    fn active_list(&self) -> &TodoList {
+        self.collection.list(self.active)
+    }
+
+    /// Returns a mutable reference to the `TodoList` that is currently active.
+    fn active_list_mut(&mut self) -> &mut TodoList {
+        self.collection.list_mut(self.active)
+    }
+
     /// Repeatedly runs `input_command()` and `exec_command()` until the user
     /// chooses to quit.
     ///
@@ -90,6 +122,13 @@ impl ProgramInterface {
                             );
                             DEFAULT_PROMPT
                         }
+                        CommandError::InsufficientLists(n) => {
+                            println!(
+                                "Needs at least {n} {}",
+                                if n == 1 { "list" } else { "lists" }
+                            );
+                            DEFAULT_PROMPT
+                        }
                         CommandError::UnknownChoice => "Unknown choice: ",
                     }
                 }
@@ -105,24 +144,84 @@ impl ProgramInterface {
     pub fn exec_command(&mut self, command: &Command) {
         match command {
             Command::Add(description) => {
-                self.todo_list.add_item(TodoItem {
+                self.active_list_mut().add_item(TodoItem {
                     description: description.to_string(),
                     ..Default::default()
                 });
-                println!("Added item #{}", self.todo_list.len());
+                println!("Added item #{}", self.active_list().len());
+            }
+            Command::Remove(index) => match self.active_list_mut().remove_item(*index) {
+                Ok(()) => println!("Removed item #{}", index + 1),
+                Err(error) => println!("Failed to remove item: {error}"),
+            },
+            Command::ToggleCompletion(index) => match self.active_list_mut().toggle_completion(*index) {
+                Ok(completed) => {
+                    let completed = if completed { "completed" } else { "incomplete" };
+                    println!("Marked item #{} as {}", index + 1, completed);
+                }
+                Err(error) => println!("Failed to toggle item: {error}"),
+            },
+            Command::Move(ix_old, ix_new) => match self.active_list_mut().move_item(*ix_old, *ix_new) {
+                Ok(()) => println!("Moved #{} to #{}", ix_old + 1, ix_new + 1),
+                Err(error) => println!("Failed to move item: {error}"),
+            },
+            Command::SetDue(index, due) => match self.active_list_mut().set_due(*index, Some(*due)) {
+                Ok(()) => println!("Set due date for item #{}", index + 1),
+                Err(error) => println!("Failed to set due date: {error}"),
+            },
+            Command::Import => {
+                println!("Paste Taskwarrior JSON tasks, one per line. Enter a blank line to finish:");
+                loop {
+                    let line = Self::input_line("");
+                    if line.is_empty() {
+                        break;
+                    }
+
+                    match import_line(&line) {
+                        Ok(item) => {
+                            self.active_list_mut().add_item(item);
+                            println!("Imported item #{}", self.active_list().len());
+                        }
+                        Err(error) => println!("Failed to import task: {error}"),
+                    }
+                }
+            }
+            Command::Export => {
+                for item in self.active_list().iter() {
+                    match export_line(item) {
+                        Ok(line) => println!("{line}"),
+                        Err(error) => println!("Failed to export task: {error}"),
+                    }
+                }
+            }
+            Command::CreateList(name) => {
+                self.collection.add_list(name.to_string());
+                self.active = self.collection.len() - 1;
+                println!("Created list \"{name}\"");
             }
-            Command::Remove(index) => {
-                self.todo_list.remove_item(*index);
-                println!("Removed item #{}", index + 1);
+            Command::SwitchList(index) => {
+                self.active = *index;
+                println!("Switched to list \"{}\"", self.collection.name(self.active));
             }
-            Command::ToggleCompletion(index) => {
-                let completed = self.todo_list.toggle_completion(*index);
-                let completed = if completed { "completed" } else { "incomplete" };
-                println!("Marked item #{} as {}", index + 1, completed);
+            Command::RenameList(index, name) => {
+                self.collection.rename_list(*index, name.to_string());
+                println!("Renamed list #{} to \"{name}\"", index + 1);
             }
-            Command::Move(ix_old, ix_new) => {
-                self.todo_list.move_item(*ix_old, *ix_new);
-                println!("Moved #{} to #{}", ix_old + 1, ix_new + 1);
+            Command::DeleteList(index) => {
+                let name = self.collection.name(*index).to_string();
+                self.collection.remove_list(*index);
+
+                if self.active >= self.collection.len() {
+                    self.active = self.collection.len() - 1;
+                } else if self.active > *index {
+                    self.active -= 1;
+                }
+
+                println!("Deleted list \"{name}\"");
+            }
+            Command::SetRenderMode(mode) => {
+                self.render_mode = mode.clone();
+                println!("Display mode updated");
             }
             Command::Help => println!("{HELP_MESSAGE}"),
             Command::Quit => (),
@@ -135,17 +234,17 @@ impl ProgramInterface {
     ///
     /// Panics if writing to `io::stdout` fails.
     pub fn print_todo_list(&self) {
-        for (i, item) in self.todo_list.iter().enumerate() {
-            let i = i + 1;
-            let checkmark = if item.completed { "[X]" } else { "[ ]" };
-            println!("{checkmark} {i}. {}", item.description);
-        }
+        println!("-- {} --", self.collection.name(self.active));
 
-        if self.todo_list.is_empty() {
+        if self.active_list().is_empty() {
             println!("No items to show");
+        } else {
+            for line in render_items(self.active_list().as_slice(), &self.render_mode) {
+                println!("{line}");
+            }
         }
 
-        let local = self.todo_list.last_updated.with_timezone(&chrono::Local);
+        let local = self.active_list().last_updated.with_timezone(&chrono::Local);
         println!("Last updated at {}", local.format("%d/%m/%Y %H:%M:%S"));
     }
 
@@ -166,7 +265,7 @@ impl ProgramInterface {
                 let description = Self::input_line("Describe your todo item: ");
                 Ok(Command::Add(description))
             }
-            2 => match self.todo_list.len().cmp(&1) {
+            2 => match self.active_list().len().cmp(&1) {
                 Ordering::Less => Err(CommandError::InsufficientItems(1)),
                 Ordering::Equal => Ok(Command::Remove(0)),
                 Ordering::Greater => {
@@ -174,7 +273,7 @@ impl ProgramInterface {
                     Ok(Command::Remove(index))
                 }
             },
-            3 => match self.todo_list.len().cmp(&1) {
+            3 => match self.active_list().len().cmp(&1) {
                 Ordering::Less => Err(CommandError::InsufficientItems(1)),
                 Ordering::Equal => Ok(Command::ToggleCompletion(0)),
                 Ordering::Greater => {
@@ -183,7 +282,7 @@ impl ProgramInterface {
                     Ok(Command::ToggleCompletion(index))
                 }
             },
-            4 => match self.todo_list.len().cmp(&2) {
+            4 => match self.active_list().len().cmp(&2) {
                 Ordering::Less => Err(CommandError::InsufficientItems(2)),
                 Ordering::Equal => Ok(Command::Move(0, 1)),
                 Ordering::Greater => {
@@ -192,7 +291,66 @@ impl ProgramInterface {
                     Ok(Command::Move(ix_old, ix_new))
                 }
             },
-            5 => Ok(Command::Help),
+            5 => match self.active_list().len().cmp(&1) {
+                Ordering::Less => Err(CommandError::InsufficientItems(1)),
+                Ordering::Equal => {
+                    let due = Self::input_due_date(
+                        "Due date (e.g. \"tomorrow\", \"in 3 days\", \"2024-06-01 14:00\"): ",
+                    );
+                    Ok(Command::SetDue(0, due))
+                }
+                Ordering::Greater => {
+                    let index = self.input_item_index("Index of an item to set a due date for: ");
+                    let due = Self::input_due_date(
+                        "Due date (e.g. \"tomorrow\", \"in 3 days\", \"2024-06-01 14:00\"): ",
+                    );
+                    Ok(Command::SetDue(index, due))
+                }
+            },
+            6 => Ok(Command::Import),
+            7 => Ok(Command::Export),
+            8 => {
+                let name = Self::input_line("Name for the new list: ");
+                Ok(Command::CreateList(name))
+            }
+            9 => match self.collection.len().cmp(&2) {
+                Ordering::Less => Err(CommandError::InsufficientLists(2)),
+                _ => {
+                    let index = self.input_list_index("Index of the list to switch to: ");
+                    Ok(Command::SwitchList(index))
+                }
+            },
+            10 => {
+                let index = if self.collection.len() == 1 {
+                    0
+                } else {
+                    self.input_list_index("Index of the list to rename: ")
+                };
+                let name = Self::input_line("New name for the list: ");
+                Ok(Command::RenameList(index, name))
+            }
+            11 => match self.collection.len().cmp(&2) {
+                Ordering::Less => Err(CommandError::InsufficientLists(2)),
+                _ => {
+                    let index = self.input_list_index("Index of the list to delete: ");
+                    Ok(Command::DeleteList(index))
+                }
+            },
+            12 => {
+                println!("1. Compact\n2. Table\n3. Custom template");
+                match Self::input_integer("Select a display mode: ") {
+                    1 => Ok(Command::SetRenderMode(RenderMode::Compact)),
+                    2 => Ok(Command::SetRenderMode(RenderMode::Table)),
+                    3 => {
+                        let template = Self::input_line(
+                            "Template (tokens: {index}, {status}, {description}, {due}): ",
+                        );
+                        Ok(Command::SetRenderMode(RenderMode::Template(template)))
+                    }
+                    _ => Err(CommandError::UnknownChoice),
+                }
+            }
+            13 => Ok(Command::Help),
             0 => Ok(Command::Quit),
             _ => Err(CommandError::UnknownChoice),
         }
@@ -233,6 +391,25 @@ impl ProgramInterface {
         }
     }
 
+    /// Prompts the user, potentially more than once, to input a due date,
+    /// accepting either a fixed format or a natural-language expression.
+    ///
+    /// # Panics
+    ///
+    /// Panics if writing to `io::stdout` fails, or if reading
+    /// from `io::stdin` fails.
+    fn input_due_date(mut prompt: &str) -> DateTime<Utc> {
+        loop {
+            let input = Self::input_line(prompt);
+            match parse_due_date(&input, Local::now()) {
+                Ok(due) => return due,
+                Err(DueDateError::Unrecognized) => {
+                    prompt = "Could not understand that due date, try again: ";
+                }
+            }
+        }
+    }
+
     /// Prompts the user, potentially more than once, to input an integer
     /// corresponding to a valid index in `todo_list`.
     ///
@@ -241,7 +418,30 @@ impl ProgramInterface {
     /// Panics if writing to `io::stdout` fails, or if reading
     /// from `io::stdin` fails.
     fn input_item_index(&self, prompt: &str) -> usize {
-        let length = self.todo_list.len();
+        let length = self.active_list().len();
+        let invalid_prompt = format!("Must be within 1 and {length}: ");
+        let mut current_prompt = prompt;
+
+        loop {
+            let n = Self::input_integer(current_prompt);
+            if n < 1 || n > length {
+                current_prompt = &invalid_prompt;
+                continue;
+            }
+
+            return n - 1;
+        }
+    }
+
+    /// Prompts the user, potentially more than once, to input an integer
+    /// corresponding to a valid index in `collection`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if writing to `io::stdout` fails, or if reading
+    /// from `io::stdin` fails.
+    fn input_list_index(&self, prompt: &str) -> usize {
+        let length = self.collection.len();
         let invalid_prompt = format!("Must be within 1 and {length}: ");
         let mut current_prompt = prompt;
 
@@ -258,11 +458,15 @@ impl ProgramInterface {
 }
 
 fn main() {
-    let todo_list = load_todo_list(TODO_LIST_FILE_PATH).unwrap_or_else(|error| match error {
+    let collection = load_todo_list(TODO_LIST_FILE_PATH).unwrap_or_else(|error| match error {
         SerdeError::IO(error) if error.kind() == io::ErrorKind::NotFound => Default::default(),
         _ => panic!("Unhandled error while loading todo list: {error:#?}"),
     });
-    let mut interface = ProgramInterface { todo_list };
+    let mut interface = ProgramInterface {
+        collection,
+        active: 0,
+        render_mode: RenderMode::default(),
+    };
 
     interface.print_todo_list();
     println!();
@@ -271,6 +475,6 @@ fn main() {
 
     interface.run_loop();
 
-    dump_todo_list(&interface.todo_list, TODO_LIST_FILE_PATH)
+    dump_todo_list(&interface.collection, TODO_LIST_FILE_PATH)
         .expect("An error occurred while saving");
 }
@@ -2,12 +2,22 @@
 
 use chrono::prelude::*;
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// An error produced by a fallible `TodoList` operation.
+#[derive(Error, Debug)]
+pub enum TodoError {
+    #[error("index {index} is out of bounds for a list of length {len}")]
+    IndexOutOfBounds { index: usize, len: usize },
+}
 
 /// A single item in a todo list.
 #[derive(Debug, Default, Deserialize, Serialize)]
 pub struct TodoItem {
     pub description: String,
     pub completed: bool,
+    #[serde(default)]
+    pub due: Option<DateTime<Utc>>,
 }
 
 /// A list of todo items.
@@ -35,6 +45,11 @@ impl TodoList {
         self.items.iter()
     }
 
+    /// Returns all `TodoItem`s in this list as a slice.
+    pub fn as_slice(&self) -> &[TodoItem] {
+        &self.items
+    }
+
     // Item manipulation
 
     /// Appends the given `TodoItem` to the end of this list.
@@ -45,40 +60,83 @@ impl TodoList {
 
     /// Moves the `TodoItem` at `ix_old` to `ix_new`.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if `ix_old` or `ix_new` is out of bounds.
-    pub fn move_item(&mut self, ix_old: usize, ix_new: usize) {
+    /// A `TodoError::IndexOutOfBounds` error is returned if `ix_old` or
+    /// `ix_new` is out of bounds.
+    pub fn move_item(&mut self, ix_old: usize, ix_new: usize) -> Result<(), TodoError> {
+        let len = self.items.len();
+        if ix_old >= len {
+            return Err(TodoError::IndexOutOfBounds { index: ix_old, len });
+        }
+        if ix_new >= len {
+            return Err(TodoError::IndexOutOfBounds { index: ix_new, len });
+        }
+
         let item = self.items.remove(ix_old);
         self.items.insert(ix_new, item);
         self.set_last_updated();
+
+        Ok(())
     }
 
     /// Removes the `TodoItem` at `index`.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if `index` is out of bounds.
-    pub fn remove_item(&mut self, index: usize) {
+    /// A `TodoError::IndexOutOfBounds` error is returned if `index` is out
+    /// of bounds.
+    pub fn remove_item(&mut self, index: usize) -> Result<(), TodoError> {
+        let len = self.items.len();
+        if index >= len {
+            return Err(TodoError::IndexOutOfBounds { index, len });
+        }
+
         self.items.remove(index);
         self.set_last_updated();
+
+        Ok(())
     }
 
     /// Toggles the `TodoItem` at the given `index` between completed and
     /// incomplete, returning its new status.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if `index` is out of bounds.
-    pub fn toggle_completion(&mut self, index: usize) -> bool {
-        let item = &mut self.items[index];
+    /// A `TodoError::IndexOutOfBounds` error is returned if `index` is out
+    /// of bounds.
+    pub fn toggle_completion(&mut self, index: usize) -> Result<bool, TodoError> {
+        let len = self.items.len();
+        let item = self
+            .items
+            .get_mut(index)
+            .ok_or(TodoError::IndexOutOfBounds { index, len })?;
 
         let updated = !item.completed;
         item.completed = updated;
 
         self.set_last_updated();
 
-        updated
+        Ok(updated)
+    }
+
+    /// Sets the due date of the `TodoItem` at the given `index`.
+    ///
+    /// # Errors
+    ///
+    /// A `TodoError::IndexOutOfBounds` error is returned if `index` is out
+    /// of bounds.
+    pub fn set_due(&mut self, index: usize, due: Option<DateTime<Utc>>) -> Result<(), TodoError> {
+        let len = self.items.len();
+        let item = self
+            .items
+            .get_mut(index)
+            .ok_or(TodoError::IndexOutOfBounds { index, len })?;
+
+        item.due = due;
+        self.set_last_updated();
+
+        Ok(())
     }
 
     /// Updates the last updated date time to the current time.
@@ -95,3 +153,102 @@ impl Default for TodoList {
         }
     }
 }
+
+/// The name given to the sole list when a `TodoCollection` is created from
+/// scratch or migrated from a single bare `TodoList`.
+pub const DEFAULT_LIST_NAME: &str = "Default";
+
+/// A `TodoList` paired with the name it is known by within a `TodoCollection`.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct NamedTodoList {
+    pub name: String,
+    pub list: TodoList,
+}
+
+/// A container holding multiple named `TodoList`s.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TodoCollection {
+    lists: Vec<NamedTodoList>,
+}
+
+impl TodoCollection {
+    /// Returns the number of lists contained in this collection.
+    pub fn len(&self) -> usize {
+        self.lists.len()
+    }
+
+    /// Returns `true` if this collection contains no lists.
+    pub fn is_empty(&self) -> bool {
+        self.lists.is_empty()
+    }
+
+    /// Returns the name of the list at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn name(&self, index: usize) -> &str {
+        &self.lists[index].name
+    }
+
+    /// Returns the `TodoList` at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn list(&self, index: usize) -> &TodoList {
+        &self.lists[index].list
+    }
+
+    /// Returns a mutable reference to the `TodoList` at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn list_mut(&mut self, index: usize) -> &mut TodoList {
+        &mut self.lists[index].list
+    }
+
+    /// Creates a new, empty list named `name`, appending it to this collection.
+    pub fn add_list(&mut self, name: String) {
+        self.lists.push(NamedTodoList {
+            name,
+            list: TodoList::default(),
+        });
+    }
+
+    /// Renames the list at `index` to `name`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn rename_list(&mut self, index: usize, name: String) {
+        self.lists[index].name = name;
+    }
+
+    /// Removes the list at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove_list(&mut self, index: usize) {
+        self.lists.remove(index);
+    }
+
+    /// Wraps a single bare `TodoList` into a collection, naming it
+    /// `DEFAULT_LIST_NAME`.
+    pub fn from_single(list: TodoList) -> Self {
+        TodoCollection {
+            lists: vec![NamedTodoList {
+                name: DEFAULT_LIST_NAME.to_string(),
+                list,
+            }],
+        }
+    }
+}
+
+impl Default for TodoCollection {
+    fn default() -> Self {
+        TodoCollection::from_single(TodoList::default())
+    }
+}
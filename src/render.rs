@@ -0,0 +1,138 @@
+//! Provides alternate rendering modes for displaying a `TodoList`'s items.
+
+use chrono::Local;
+
+use crate::models::TodoItem;
+
+/// The rendering mode used to display a `TodoList`'s items.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum RenderMode {
+    /// A single compact line per item.
+    #[default]
+    Compact,
+    /// A bordered table with aligned columns.
+    Table,
+    /// A user-supplied template, with `{index}`, `{status}`,
+    /// `{description}`, and `{due}` substituted per item.
+    Template(String),
+}
+
+/// Renders `items` according to `mode`, returning one line of output per
+/// item (or, for `RenderMode::Table`, per row of the rendered table).
+pub fn render_items(items: &[TodoItem], mode: &RenderMode) -> Vec<String> {
+    match mode {
+        RenderMode::Compact => render_compact(items),
+        RenderMode::Table => render_table(items),
+        RenderMode::Template(template) => render_template(items, template),
+    }
+}
+
+/// Formats an item's status as a checkbox string.
+fn format_status(item: &TodoItem) -> &'static str {
+    if item.completed {
+        "[X]"
+    } else {
+        "[ ]"
+    }
+}
+
+/// Formats an item's due date, flagging it as overdue where applicable,
+/// or an empty string if it has none.
+fn format_due(item: &TodoItem) -> String {
+    let Some(due) = item.due else {
+        return String::new();
+    };
+
+    let local = due.with_timezone(&Local).format("%d/%m/%Y %H:%M");
+    if due < chrono::Utc::now() && !item.completed {
+        format!("{local} (OVERDUE)")
+    } else {
+        local.to_string()
+    }
+}
+
+fn render_compact(items: &[TodoItem]) -> Vec<String> {
+    items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let due = format_due(item);
+            if due.is_empty() {
+                format!("{} {}. {}", format_status(item), i + 1, item.description)
+            } else {
+                format!(
+                    "{} {}. {} (due {due})",
+                    format_status(item),
+                    i + 1,
+                    item.description
+                )
+            }
+        })
+        .collect()
+}
+
+fn render_table(items: &[TodoItem]) -> Vec<String> {
+    const HEADERS: [&str; 4] = ["#", "Status", "Description", "Due"];
+
+    let rows: Vec<[String; 4]> = items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            [
+                (i + 1).to_string(),
+                format_status(item).to_string(),
+                item.description.clone(),
+                format_due(item),
+            ]
+        })
+        .collect();
+
+    let widths: [usize; 4] = std::array::from_fn(|col| {
+        rows.iter()
+            .map(|row| row[col].len())
+            .chain(std::iter::once(HEADERS[col].len()))
+            .max()
+            .unwrap_or(0)
+    });
+
+    let border = format!(
+        "+{}+",
+        widths
+            .iter()
+            .map(|width| "-".repeat(width + 2))
+            .collect::<Vec<_>>()
+            .join("+")
+    );
+
+    let format_row = |cells: &[String; 4]| {
+        format!(
+            "| {} |",
+            cells
+                .iter()
+                .zip(widths.iter())
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect::<Vec<_>>()
+                .join(" | ")
+        )
+    };
+
+    let mut lines = vec![border.clone(), format_row(&HEADERS.map(String::from)), border.clone()];
+    lines.extend(rows.iter().map(format_row));
+    lines.push(border);
+
+    lines
+}
+
+fn render_template(items: &[TodoItem], template: &str) -> Vec<String> {
+    items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            template
+                .replace("{index}", &(i + 1).to_string())
+                .replace("{status}", format_status(item))
+                .replace("{description}", &item.description)
+                .replace("{due}", &format_due(item))
+        })
+        .collect()
+}
@@ -2,6 +2,7 @@
 
 use std::fs;
 use std::io;
+use std::path::Path;
 
 use thiserror::Error;
 
@@ -17,27 +18,80 @@ pub enum SerdeError {
     Parse(#[from] toml::de::Error),
     #[error("failed to serialize todo list")]
     Format(#[from] toml::ser::Error),
+    #[error("failed to parse file contents")]
+    JsonParse(serde_json::Error),
+    #[error("failed to serialize todo list")]
+    JsonFormat(serde_json::Error),
+}
+
+/// The on-disk serialization format used to store a `TodoCollection`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Toml,
+    Json,
+}
+
+impl Format {
+    /// Determines the `Format` to use for `filepath` based on its
+    /// extension, defaulting to `Format::Toml` if the extension is
+    /// missing or unrecognized.
+    pub fn from_filepath(filepath: &str) -> Self {
+        match Path::new(filepath).extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Format::Json,
+            _ => Format::Toml,
+        }
+    }
 }
 
-/// Loads a `TodoList` instance in TOML format from the given `filepath`.
+/// Loads a `TodoCollection` instance from the given `filepath`, choosing
+/// TOML or JSON based on its extension (see `Format::from_filepath`).
+///
+/// For backward compatibility, a file holding a single bare `TodoList`
+/// (the format used before multiple lists were supported) is also
+/// accepted and wrapped into a collection named `DEFAULT_LIST_NAME`.
 ///
 /// # Errors
 ///
-/// A `SerdeError::IO` or `SerdeError::Parse` error may occur if either
-/// operation fails during this process.
-pub fn load_todo_list(filepath: &str) -> Result<TodoList, SerdeError> {
+/// A `SerdeError::IO`, `SerdeError::Parse`, or `SerdeError::JsonParse`
+/// error may occur if either operation fails during this process.
+pub fn load_todo_list(filepath: &str) -> Result<TodoCollection, SerdeError> {
     let contents = fs::read_to_string(filepath)?;
-    Ok(toml::from_str::<TodoList>(&contents)?)
+
+    match Format::from_filepath(filepath) {
+        Format::Toml => {
+            if let Ok(collection) = toml::from_str::<TodoCollection>(&contents) {
+                return Ok(collection);
+            }
+
+            let todo_list = toml::from_str::<TodoList>(&contents)?;
+            Ok(TodoCollection::from_single(todo_list))
+        }
+        Format::Json => {
+            if let Ok(collection) = serde_json::from_str::<TodoCollection>(&contents) {
+                return Ok(collection);
+            }
+
+            let todo_list =
+                serde_json::from_str::<TodoList>(&contents).map_err(SerdeError::JsonParse)?;
+            Ok(TodoCollection::from_single(todo_list))
+        }
+    }
 }
 
-/// Dumps the given `TodoList` instance to `filepath` in TOML format.
+/// Dumps the given `TodoCollection` instance to `filepath`, choosing TOML
+/// or JSON based on its extension (see `Format::from_filepath`).
 ///
 /// # Errors
 ///
-/// A `SerdeError::IO` or `SerdeError::Format` error may occur if either
-/// operation fails during this process.
-pub fn dump_todo_list(todo_list: &TodoList, filepath: &str) -> Result<(), SerdeError> {
-    let contents = toml::ser::to_string(todo_list)?;
+/// A `SerdeError::IO`, `SerdeError::Format`, or `SerdeError::JsonFormat`
+/// error may occur if either operation fails during this process.
+pub fn dump_todo_list(collection: &TodoCollection, filepath: &str) -> Result<(), SerdeError> {
+    let contents = match Format::from_filepath(filepath) {
+        Format::Toml => toml::ser::to_string(collection)?,
+        Format::Json => {
+            serde_json::to_string_pretty(collection).map_err(SerdeError::JsonFormat)?
+        }
+    };
     fs::write(filepath, contents)?;
     Ok(())
 }
@@ -0,0 +1,70 @@
+//! Provides interoperability with Taskwarrior's JSON task export format.
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::models::TodoItem;
+
+/// The date time format used by Taskwarrior for its `due` field.
+const TASKWARRIOR_DATE_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+/// An error produced while importing or exporting Taskwarrior-formatted tasks.
+#[derive(Error, Debug)]
+pub enum TaskwarriorError {
+    #[error("failed to parse task JSON")]
+    Json(#[from] serde_json::Error),
+    #[error("failed to parse Taskwarrior due date")]
+    InvalidDueDate,
+}
+
+/// A single task in Taskwarrior's JSON export format.
+#[derive(Debug, Deserialize, Serialize)]
+struct TaskwarriorTask {
+    description: String,
+    status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    due: Option<String>,
+}
+
+/// Parses a single line of Taskwarrior JSON into a `TodoItem`.
+///
+/// # Errors
+///
+/// A `TaskwarriorError` is returned if `line` is not valid JSON or its
+/// `due` field cannot be parsed.
+pub fn import_line(line: &str) -> Result<TodoItem, TaskwarriorError> {
+    let task: TaskwarriorTask = serde_json::from_str(line)?;
+
+    let due = task
+        .due
+        .map(|due| {
+            NaiveDateTime::parse_from_str(&due, TASKWARRIOR_DATE_FORMAT)
+                .map(|dt| dt.and_utc())
+                .map_err(|_| TaskwarriorError::InvalidDueDate)
+        })
+        .transpose()?;
+
+    Ok(TodoItem {
+        description: task.description,
+        completed: task.status == "completed",
+        due,
+    })
+}
+
+/// Serializes a `TodoItem` into a single line of Taskwarrior JSON.
+///
+/// # Errors
+///
+/// A `TaskwarriorError` is returned if serialization fails.
+pub fn export_line(item: &TodoItem) -> Result<String, TaskwarriorError> {
+    let task = TaskwarriorTask {
+        description: item.description.clone(),
+        status: if item.completed { "completed" } else { "pending" }.to_string(),
+        due: item
+            .due
+            .map(|due| due.format(TASKWARRIOR_DATE_FORMAT).to_string()),
+    };
+
+    Ok(serde_json::to_string(&task)?)
+}